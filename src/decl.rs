@@ -0,0 +1,138 @@
+use crate::expr::{Expr, StringLit};
+use crate::pat::Pat;
+use crate::{Class, Func, Ident, VarKind};
+
+/// A variable, function, class, import or export declaration.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum Decl<'a> {
+    Variable(VarKind, Vec<VarDecl<'a>>),
+    Function(Func<'a>),
+    Class(Class<'a>),
+    Import(Box<ModImport<'a>>),
+    Export(Box<ModExport<'a>>),
+}
+
+/// A single `id = init` binding, e.g. the `a = 1` in `let a = 1, b;`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct VarDecl<'a> {
+    pub id: Pat<'a>,
+    pub init: Option<Expr<'a>>,
+}
+
+/// Either half of a module declaration; mostly useful as a single type to
+/// match on when you don't care whether you have an import or an export.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum ModDecl<'a> {
+    Import(ModImport<'a>),
+    Export(ModExport<'a>),
+}
+
+/// `import specifiers from 'source'`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct ModImport<'a> {
+    pub source: StringLit<'a>,
+    pub specifiers: Vec<ImportSpecifier<'a>>,
+}
+
+/// A single imported binding.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum ImportSpecifier<'a> {
+    Normal(NormalImportSpec<'a>),
+    Default(Ident<'a>),
+    Namespace(Ident<'a>),
+}
+
+/// `{ imported as local }`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct NormalImportSpec<'a> {
+    pub imported: Ident<'a>,
+    pub local: Ident<'a>,
+}
+
+/// `export default ...`, `export { a, b }`, `export * from '...'`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum ModExport<'a> {
+    Default(DefaultExportDecl<'a>),
+    Named(NamedExportDecl<'a>),
+    All { source: StringLit<'a> },
+}
+
+/// The thing after `export default`: either a full declaration
+/// (`export default function f() {}`) or a bare expression
+/// (`export default 1 + 1`).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum DefaultExportDecl<'a> {
+    Decl(Decl<'a>),
+    Expr(Expr<'a>),
+}
+
+/// `export { a, b as c }`, optionally re-exported `from 'source'`, or a
+/// wrapped local `export const a = 1`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct NamedExportDecl<'a> {
+    pub decl: Option<Decl<'a>>,
+    pub specifiers: Vec<ExportSpecifier<'a>>,
+    pub source: Option<StringLit<'a>>,
+}
+
+/// A single `local as exported` entry of a [`NamedExportDecl`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct ExportSpecifier<'a> {
+    pub local: Ident<'a>,
+    pub exported: Ident<'a>,
+}
@@ -0,0 +1,391 @@
+use std::borrow::Cow;
+
+#[cfg(feature = "jsx")]
+use crate::jsx::{JSXElement, JSXFragment};
+use crate::pat::Pat;
+use crate::{
+    AssignOp, BinaryOp, Class, ExprNode, Func, FuncArg, FuncBody, Ident, LogicalOp, PropKind,
+    UnaryOp, UpdateOp,
+};
+
+/// Any Javascript expression.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum Expr<'a> {
+    Array(ArrayExpr<'a>),
+    ArrowFunc(ArrowFuncExpr<'a>),
+    Assign(AssignExpr<'a>),
+    Await(Box<Expr<'a>>),
+    Binary(BinaryExpr<'a>),
+    Class(Class<'a>),
+    Call(CallExpr<'a>),
+    Conditional(ConditionalExpr<'a>),
+    Func(Func<'a>),
+    Ident(Ident<'a>),
+    Lit(Lit<'a>),
+    Logical(LogicalExpr<'a>),
+    Member(MemberExpr<'a>),
+    MetaProp(MetaProp<'a>),
+    New(NewExpr<'a>),
+    Obj(ObjExpr<'a>),
+    Sequence(Vec<Expr<'a>>),
+    Spread(Box<Expr<'a>>),
+    Super,
+    TaggedTemplate(TaggedTemplateExpr<'a>),
+    This,
+    Unary(UnaryExpr<'a>),
+    Update(UpdateExpr<'a>),
+    /// A parenthesized expression, e.g. `(a, b)`.
+    Wrapped(Box<Expr<'a>>),
+    Yield(YieldExpr<'a>),
+    /// A JSX element, e.g. `<div id="a">{child}</div>`.
+    #[cfg(feature = "jsx")]
+    JSXElement(Box<JSXElement<'a>>),
+    /// A JSX fragment, e.g. `<>{children}</>`.
+    #[cfg(feature = "jsx")]
+    JSXFragment(Box<JSXFragment<'a>>),
+}
+
+/// `[a, , b]`, a sparse array of expressions.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct ArrayExpr<'a>(pub Vec<Option<Expr<'a>>>);
+
+/// `(a, b) => a + b`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct ArrowFuncExpr<'a> {
+    pub id: Option<Ident<'a>>,
+    pub params: Vec<FuncArg<'a>>,
+    pub body: ArrowFuncBody<'a>,
+    pub expression: bool,
+    pub generator: bool,
+    pub is_async: bool,
+}
+
+/// The body of an arrow function, either a block or a single expression.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum ArrowFuncBody<'a> {
+    Expr(Box<Expr<'a>>),
+    FuncBody(FuncBody<'a>),
+}
+
+/// `a = b`, `a += b`, etc.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct AssignExpr<'a> {
+    pub operator: AssignOp,
+    pub left: AssignLeft<'a>,
+    pub right: ExprNode<'a>,
+}
+
+/// The left-hand side of an [`AssignExpr`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum AssignLeft<'a> {
+    Pat(Pat<'a>),
+    Expr(ExprNode<'a>),
+}
+
+/// `a + b`, `a instanceof b`, etc.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct BinaryExpr<'a> {
+    pub operator: BinaryOp,
+    pub left: ExprNode<'a>,
+    pub right: ExprNode<'a>,
+}
+
+/// `callee(arguments)`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct CallExpr<'a> {
+    pub callee: Box<Expr<'a>>,
+    pub arguments: Vec<Expr<'a>>,
+}
+
+/// `test ? consequent : alternate`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct ConditionalExpr<'a> {
+    pub test: Box<Expr<'a>>,
+    pub alternate: Box<Expr<'a>>,
+    pub consequent: Box<Expr<'a>>,
+}
+
+/// A literal value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum Lit<'a> {
+    Null,
+    String(StringLit<'a>),
+    Number(Cow<'a, str>),
+    Boolean(bool),
+    RegEx(RegEx<'a>),
+    /// A template literal, e.g. `` `a${b}c` ``.
+    Template(TemplateLit<'a>),
+}
+
+/// A single- or double-quoted string literal.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum StringLit<'a> {
+    Single(Cow<'a, str>),
+    Double(Cow<'a, str>),
+}
+
+/// `/pattern/flags`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct RegEx<'a> {
+    pub pattern: Cow<'a, str>,
+    pub flags: Cow<'a, str>,
+}
+
+/// `` `head${expressions[0]}...tail` ``
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct TemplateLit<'a> {
+    /// The static string chunks, one more than `expressions`.
+    pub quasis: Vec<TemplateElement<'a>>,
+    /// The interpolated `${..}` expressions, in source order.
+    pub expressions: Vec<Expr<'a>>,
+}
+
+/// One static chunk of a [`TemplateLit`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct TemplateElement<'a> {
+    pub tail: bool,
+    pub cooked: Cow<'a, str>,
+    pub raw: Cow<'a, str>,
+}
+
+/// `a && b`, `a || b`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct LogicalExpr<'a> {
+    pub operator: LogicalOp,
+    pub left: ExprNode<'a>,
+    pub right: ExprNode<'a>,
+}
+
+/// `object.property` or `object[property]`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct MemberExpr<'a> {
+    pub object: Box<Expr<'a>>,
+    pub property: Box<Expr<'a>>,
+    pub computed: bool,
+}
+
+/// `new.target`, `import.meta`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct MetaProp<'a> {
+    pub meta: Ident<'a>,
+    pub property: Ident<'a>,
+}
+
+/// `new callee(arguments)`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct NewExpr<'a> {
+    pub callee: Box<Expr<'a>>,
+    pub arguments: Vec<Expr<'a>>,
+}
+
+/// `{ a: 1, ...b }`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct ObjExpr<'a>(pub Vec<ObjProp<'a>>);
+
+/// A single member of an [`ObjExpr`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum ObjProp<'a> {
+    Prop(Prop<'a>),
+    Spread(Expr<'a>),
+}
+
+/// A single `key: value` (or shorthand/method) entry of an object or
+/// class body.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct Prop<'a> {
+    pub key: PropKey<'a>,
+    pub value: PropValue<'a>,
+    pub kind: PropKind,
+    pub method: bool,
+    pub computed: bool,
+    pub short_hand: bool,
+}
+
+/// The key of a [`Prop`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum PropKey<'a> {
+    Lit(Lit<'a>),
+    Ident(Ident<'a>),
+    Pat(Pat<'a>),
+}
+
+/// The value of a [`Prop`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum PropValue<'a> {
+    Expr(Expr<'a>),
+    Pat(Pat<'a>),
+    None,
+}
+
+/// `` tag`template` ``
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct TaggedTemplateExpr<'a> {
+    pub tag: Box<Expr<'a>>,
+    pub quasi: TemplateLit<'a>,
+}
+
+/// `!a`, `typeof a`, etc.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct UnaryExpr<'a> {
+    pub operator: UnaryOp,
+    pub prefix: bool,
+    pub argument: Box<Expr<'a>>,
+}
+
+/// `a++`, `--a`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct UpdateExpr<'a> {
+    pub operator: UpdateOp,
+    pub prefix: bool,
+    pub argument: Box<Expr<'a>>,
+}
+
+/// `yield a`, `yield* a`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct YieldExpr<'a> {
+    pub argument: Option<Box<Expr<'a>>>,
+    pub delegate: bool,
+}
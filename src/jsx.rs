@@ -0,0 +1,159 @@
+//! JSX nodes, feature-gated behind `jsx` so non-JSX consumers pay nothing
+//! for them.
+//!
+//! These extend [`Expr`](crate::expr::Expr) with `Expr::JSXElement` and
+//! `Expr::JSXFragment` variants, so JSX can appear anywhere a normal
+//! expression can (`<div/>` as a call argument, an arrow function body,
+//! and so on).
+use std::borrow::Cow;
+
+use crate::expr::Expr;
+use crate::Ident;
+
+/// A JSX element: `<div id="a" {...rest}>{child}</div>`, or its
+/// self-closing form `<div id="a" />`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct JSXElement<'a> {
+    pub opening_element: JSXOpeningElement<'a>,
+    pub children: Vec<JSXChild<'a>>,
+    /// `None` for a self-closing element.
+    pub closing_element: Option<JSXClosingElement<'a>>,
+}
+
+/// The opening (or self-closing) tag of a [`JSXElement`]: `<div id="a">`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct JSXOpeningElement<'a> {
+    pub name: JSXElementName<'a>,
+    pub attributes: Vec<JSXAttributeItem<'a>>,
+    pub self_closing: bool,
+}
+
+/// The closing tag of a [`JSXElement`]: `</div>`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct JSXClosingElement<'a> {
+    pub name: JSXElementName<'a>,
+}
+
+/// A tag name, which may be a plain identifier (`div`) or a dotted
+/// member path (`Foo.Bar`).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub enum JSXElementName<'a> {
+    Ident(Ident<'a>),
+    Member(Vec<Ident<'a>>),
+}
+
+/// One attribute on an opening element: either `name="value"`/`name` or
+/// a spread, `{...rest}`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum JSXAttributeItem<'a> {
+    Attribute(JSXAttribute<'a>),
+    Spread(JSXSpreadAttribute<'a>),
+}
+
+/// A single `name="value"` or valueless `name` attribute.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct JSXAttribute<'a> {
+    pub name: Ident<'a>,
+    pub value: Option<JSXAttributeValue<'a>>,
+}
+
+/// The right-hand side of a [`JSXAttribute`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum JSXAttributeValue<'a> {
+    Str(Cow<'a, str>),
+    Container(JSXExpressionContainer<'a>),
+    Element(Box<JSXElement<'a>>),
+}
+
+/// A `{...rest}` spread attribute.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct JSXSpreadAttribute<'a> {
+    pub argument: Box<Expr<'a>>,
+}
+
+/// An embedded expression: `{expr}`, used both as a child and as an
+/// attribute value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct JSXExpressionContainer<'a>(pub Box<Expr<'a>>);
+
+/// Raw text between JSX tags.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct JSXText<'a>(pub Cow<'a, str>);
+
+/// A fragment, `<>{children}</>`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct JSXFragment<'a> {
+    pub children: Vec<JSXChild<'a>>,
+}
+
+/// Anything that can appear between an element's opening and closing tags.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum JSXChild<'a> {
+    Element(JSXElement<'a>),
+    Fragment(JSXFragment<'a>),
+    ExpressionContainer(JSXExpressionContainer<'a>),
+    Text(JSXText<'a>),
+}
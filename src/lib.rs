@@ -6,15 +6,23 @@ use std::borrow::Cow;
 
 pub mod decl;
 pub mod expr;
+#[cfg(feature = "jsx")]
+pub mod jsx;
+pub mod loc;
 pub mod pat;
 #[cfg(feature = "esprima")]
 pub mod serde;
 pub mod stmt;
+#[cfg(feature = "ts")]
+pub mod ts;
+pub mod visit;
 
 use decl::Decl;
 use expr::{Expr, Lit, Prop};
 use pat::Pat;
 use stmt::Stmt;
+#[cfg(feature = "ts")]
+use ts::{TsTypeAnnotation, TsTypeParamDecl};
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 #[cfg_attr(
@@ -35,9 +43,129 @@ pub struct SourcePos {
 #[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
 pub struct SourceSpan {
     pub start: SourcePos,
+    pub end: SourcePos,
+    /// Index into the set of files a multi-file parse was assembled from.
+    /// Single-file callers can leave this at its default of `0`.
+    pub file: u32,
     pub in_map: bool,
 }
 
+impl SourceSpan {
+    /// Collapses this span to a zero-width point at its `start`.
+    pub fn begin_range(&self) -> SourceSpan {
+        SourceSpan {
+            start: self.start.clone(),
+            end: self.start.clone(),
+            file: self.file,
+            in_map: self.in_map,
+        }
+    }
+
+    /// Collapses this span to a zero-width point at its `end`.
+    pub fn end_range(&self) -> SourceSpan {
+        SourceSpan {
+            start: self.end.clone(),
+            end: self.end.clone(),
+            file: self.file,
+            in_map: self.in_map,
+        }
+    }
+}
+
+/// A node annotated with the source location it was parsed from.
+///
+/// `Spanned<T>` derefs to `T`, so it can be used more or less like the
+/// wrapped node itself, with `span()` as the one extra thing you can ask
+/// it for. The node is boxed so that opting a recursive field (e.g. a
+/// `Box<Expr>` or a `Vec<ProgramPart>` entry) into carrying a span
+/// doesn't grow the size of every enum variant that doesn't.
+///
+/// This type exists only with the `spans` feature. With the feature on,
+/// [`ExprNode`] and [`ProgramPartNode`] fields are `Spanned`; with it off
+/// they are the plain node types, so code that builds nodes without
+/// spans keeps compiling unchanged.
+#[cfg(feature = "spans")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "esprima")),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct Spanned<T> {
+    node: Box<T>,
+    span: SourceSpan,
+}
+
+#[cfg(feature = "spans")]
+impl<T> Spanned<T> {
+    pub fn new(node: T, span: SourceSpan) -> Self {
+        Spanned {
+            node: Box::new(node),
+            span,
+        }
+    }
+
+    pub fn span(&self) -> &SourceSpan {
+        &self.span
+    }
+
+    pub fn into_inner(self) -> T {
+        *self.node
+    }
+}
+
+#[cfg(feature = "spans")]
+impl<T> std::ops::Deref for Spanned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.node
+    }
+}
+
+#[cfg(feature = "spans")]
+impl<T> std::ops::DerefMut for Spanned<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.node
+    }
+}
+
+/// The type used for a boxed child `Expr` in a recursive field.
+///
+/// With the `spans` feature on, the child carries its own [`SourceSpan`];
+/// otherwise it's a plain `Box<Expr>`. Both sides `Deref` to `Expr`, so
+/// callers (e.g. [`crate::visit`]) can treat the two identically.
+///
+/// Used for the operand positions of binary-like expressions
+/// ([`crate::expr::BinaryExpr`], [`crate::expr::LogicalExpr`]), an
+/// assignment's target and value ([`crate::expr::AssignLeft::Expr`],
+/// `AssignExpr::right`), and a class's `super_class` — the positions
+/// a diagnostic is most likely to need a span for. Other `Box<Expr>`
+/// fields stay unwrapped until something needs their span too.
+#[cfg(feature = "spans")]
+pub type ExprNode<'a> = Spanned<Expr<'a>>;
+#[cfg(not(feature = "spans"))]
+pub type ExprNode<'a> = Box<Expr<'a>>;
+
+/// The type used for a [`ProgramPart`] in a recursive position, e.g. the
+/// body of a [`Program`] or [`FuncBody`]. See [`ExprNode`].
+#[cfg(feature = "spans")]
+pub type ProgramPartNode<'a> = Spanned<ProgramPart<'a>>;
+#[cfg(not(feature = "spans"))]
+pub type ProgramPartNode<'a> = ProgramPart<'a>;
+
+/// Wraps an `Expr` for a recursive field, attaching a default (empty)
+/// span when `spans` is on. Constructors that don't yet track real
+/// positions (e.g. [`Class::new`]) use this rather than a real span.
+#[cfg(feature = "spans")]
+pub(crate) fn expr_node(expr: Expr<'_>) -> ExprNode<'_> {
+    Spanned::new(expr, SourceSpan::default())
+}
+#[cfg(not(feature = "spans"))]
+pub(crate) fn expr_node(expr: Expr<'_>) -> ExprNode<'_> {
+    Box::new(expr)
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
     all(feature = "serde", not(feature = "esprima")),
@@ -47,6 +175,10 @@ pub struct SourceSpan {
 pub struct Ident<'a> {
     pub name: Cow<'a, str>,
     pub s_loc: SourceSpan,
+    /// A TypeScript `: T` annotation, e.g. on a parameter or variable
+    /// identifier. The field exists only with the `ts` feature.
+    #[cfg(feature = "ts")]
+    pub type_annotation: Option<TsTypeAnnotation<'a>>,
 }
 
 impl<'a> Ident<'a> {
@@ -54,13 +186,17 @@ impl<'a> Ident<'a> {
         Ident {
             name: Cow::Owned(s),
             s_loc,
+            #[cfg(feature = "ts")]
+            type_annotation: None,
         }
     }
 
     pub fn from_with_span(s: &'a str, s_loc: SourceSpan) -> Self {
         Ident {
             name: Cow::Borrowed(s),
-            s_loc
+            s_loc,
+            #[cfg(feature = "ts")]
+            type_annotation: None,
         }
     }
 
@@ -68,15 +204,39 @@ impl<'a> Ident<'a> {
         Ident {
             name: Cow::Borrowed(s),
             s_loc: SourceSpan::default(),
+            #[cfg(feature = "ts")]
+            type_annotation: None,
         }
     }
 
     pub fn from_with_pos(s: &'a str, line: u32, column: u32) -> Self {
+        let start = SourcePos {
+            line: line - 1,
+            col: column - 1,
+        };
+        let end = SourcePos {
+            line: start.line,
+            col: start.col + s.chars().count() as u32,
+        };
         Ident {
             name: Cow::Borrowed(s),
-            s_loc: SourceSpan { start: SourcePos { line: line - 1, col: column - 1 }, in_map: true },
+            s_loc: SourceSpan {
+                start,
+                end,
+                file: 0,
+                in_map: true,
+            },
+            #[cfg(feature = "ts")]
+            type_annotation: None,
         }
     }
+
+    /// Attaches a TypeScript type annotation to this identifier.
+    #[cfg(feature = "ts")]
+    pub fn with_type_annotation(mut self, annotation: TsTypeAnnotation<'a>) -> Self {
+        self.type_annotation = Some(annotation);
+        self
+    }
 }
 
 /// A fully parsed javascript program.
@@ -92,18 +252,26 @@ impl<'a> Ident<'a> {
 #[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
 pub enum Program<'a> {
     /// An ES6 Mod
-    Mod(Vec<ProgramPart<'a>>),
+    Mod(Vec<ProgramPartNode<'a>>),
     /// Not an ES6 Mod
-    Script(Vec<ProgramPart<'a>>),
+    Script(Vec<ProgramPartNode<'a>>),
 }
 
 impl<'a> Program<'a> {
-    pub fn module(parts: Vec<ProgramPart<'a>>) -> Self {
+    pub fn module(parts: Vec<ProgramPartNode<'a>>) -> Self {
         Program::Mod(parts)
     }
-    pub fn script(parts: Vec<ProgramPart<'a>>) -> Self {
+    pub fn script(parts: Vec<ProgramPartNode<'a>>) -> Self {
         Program::Script(parts)
     }
+
+    /// The parts of this program, regardless of whether it's a module
+    /// or a script.
+    pub fn parts(&self) -> &[ProgramPartNode<'a>] {
+        match self {
+            Program::Mod(parts) | Program::Script(parts) => parts,
+        }
+    }
 }
 
 /// A single part of a Javascript program.
@@ -164,6 +332,14 @@ pub struct Func<'a> {
     pub body: FuncBody<'a>,
     pub generator: bool,
     pub is_async: bool,
+    /// The declared `<T, U>` type parameters, if any. The field exists
+    /// only with the `ts` feature.
+    #[cfg(feature = "ts")]
+    pub type_params: Option<TsTypeParamDecl<'a>>,
+    /// The declared `: T` return type, if any. The field exists only
+    /// with the `ts` feature.
+    #[cfg(feature = "ts")]
+    pub return_type: Option<TsTypeAnnotation<'a>>,
 }
 
 impl<'a> Func<'a> {
@@ -180,11 +356,22 @@ impl<'a> Func<'a> {
             body,
             generator,
             is_async,
+            #[cfg(feature = "ts")]
+            type_params: None,
+            #[cfg(feature = "ts")]
+            return_type: None,
         }
     }
 }
 
-/// A single function argument from a function signature
+/// A single function argument from a function signature.
+///
+/// This has no `ts`-gated type-annotation field of its own: a
+/// `FuncArg::Pat(Pat::Ident(id))` already carries its `: T` through
+/// `id.type_annotation`, so adding a second one here would just be two
+/// places to keep in sync. `FuncArg::Expr` represents a default-valued
+/// or otherwise non-identifier argument shape, which TypeScript doesn't
+/// attach a standalone type annotation to either.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
     all(feature = "serde", not(feature = "esprima")),
@@ -213,7 +400,7 @@ impl<'a> FuncArg<'a> {
     derive(Deserialize, Serialize)
 )]
 #[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
-pub struct FuncBody<'a>(pub Vec<ProgramPart<'a>>);
+pub struct FuncBody<'a>(pub Vec<ProgramPartNode<'a>>);
 /// A way to declare object templates
 /// ```js
 /// class Thing {
@@ -244,8 +431,12 @@ pub struct FuncBody<'a>(pub Vec<ProgramPart<'a>>);
 #[cfg_attr(all(feature = "serialization"), derive(Deserialize, Serialize))]
 pub struct Class<'a> {
     pub id: Option<Ident<'a>>,
-    pub super_class: Option<Box<Expr<'a>>>,
+    pub super_class: Option<ExprNode<'a>>,
     pub body: ClassBody<'a>,
+    /// The declared `<T, U>` type parameters, if any. The field exists
+    /// only with the `ts` feature.
+    #[cfg(feature = "ts")]
+    pub type_params: Option<TsTypeParamDecl<'a>>,
 }
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
@@ -263,8 +454,10 @@ impl<'a> Class<'a> {
     ) -> Class<'a> {
         Class {
             id,
-            super_class: super_class.map(Box::new),
+            super_class: super_class.map(expr_node),
             body: ClassBody(body),
+            #[cfg(feature = "ts")]
+            type_params: None,
         }
     }
 }
@@ -419,6 +612,21 @@ pub mod prelude {
         BlockStmt, CatchClause, DoWhileStmt, ForInStmt, ForOfStmt, ForStmt, IfStmt, LabeledStmt,
         LoopInit, LoopLeft, Stmt, SwitchCase, SwitchStmt, TryStmt, WhileStmt, WithStmt,
     };
+    pub use crate::loc::{CodeLocation, OptionalCodeLocation};
+    #[cfg(feature = "jsx")]
+    pub use crate::jsx::{
+        JSXAttribute, JSXAttributeItem, JSXAttributeValue, JSXChild, JSXClosingElement,
+        JSXElement, JSXElementName, JSXExpressionContainer, JSXFragment, JSXOpeningElement,
+        JSXSpreadAttribute, JSXText,
+    };
+    #[cfg(feature = "ts")]
+    pub use crate::ts::{
+        TsArrayType, TsFunctionType, TsIntersection, TsPropertySignature, TsType, TsTypeAnnotation,
+        TsTypeLiteral, TsTypeParamDecl, TsTypeRef, TsTupleType, TsUnion,
+    };
+    pub use crate::visit::{Visit, VisitMut};
+    #[cfg(feature = "spans")]
+    pub use crate::{ExprNode, ProgramPartNode, Spanned};
     pub use crate::{
         AssignOp, BinaryOp, Class, ClassBody, Dir, Func, FuncArg, FuncBody, Ident, LogicalOp,
         Program, ProgramPart, PropKind, SourceSpan, UnaryOp, UpdateOp, VarKind,
@@ -0,0 +1,62 @@
+//! A uniform way to ask an AST node "where are you?"
+//!
+//! Without this, locating a node means matching on its concrete type to
+//! reach whichever field happens to hold its [`SourceSpan`]. [`CodeLocation`]
+//! gives every node with a span the same `loc()` method, so tooling (a
+//! diagnostic reporter, or a [`Visit`](crate::visit::Visit) implementation)
+//! can ask for a node's location without caring which variant it is.
+//! [`OptionalCodeLocation`] is the same idea for nodes whose span may not
+//! be present yet.
+use crate::decl::Decl;
+use crate::pat::Pat;
+#[cfg(feature = "spans")]
+use crate::Spanned;
+use crate::{Ident, ProgramPart, SourceSpan};
+
+/// A node that always knows its own source location.
+pub trait CodeLocation {
+    /// Returns the span this node occupies in the source it was parsed from.
+    fn loc(&self) -> SourceSpan;
+}
+
+/// A node whose source location may not be available.
+pub trait OptionalCodeLocation {
+    /// Returns the span this node occupies, if it has one.
+    fn loc(&self) -> Option<SourceSpan>;
+}
+
+impl<'a> CodeLocation for Ident<'a> {
+    fn loc(&self) -> SourceSpan {
+        self.s_loc.clone()
+    }
+}
+
+/// A [`Spanned`] node always has a span, by construction.
+#[cfg(feature = "spans")]
+impl<T> CodeLocation for Spanned<T> {
+    fn loc(&self) -> SourceSpan {
+        self.span().clone()
+    }
+}
+
+/// A [`ProgramPart`] has no span of its own; this reports the span of
+/// the identifier that names it, where one exists (e.g. a named function
+/// or class declaration, or the first declarator of a `var`/`let`/`const`).
+/// Directives, statements, imports and exports have no such identifier,
+/// so this is `None` for them.
+impl<'a> OptionalCodeLocation for ProgramPart<'a> {
+    fn loc(&self) -> Option<SourceSpan> {
+        let ProgramPart::Decl(decl) = self else {
+            return None;
+        };
+        match decl {
+            Decl::Variable(_kind, decls) => decls.first().and_then(|decl| match &decl.id {
+                Pat::Ident(ident) => Some(ident.loc()),
+                _ => None,
+            }),
+            Decl::Function(func) => func.id.as_ref().map(CodeLocation::loc),
+            Decl::Class(class) => class.id.as_ref().map(CodeLocation::loc),
+            Decl::Import(_) | Decl::Export(_) => None,
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! TypeScript type-annotation nodes, feature-gated behind `ts` so a
+//! plain-JS consumer pays nothing for them.
+//!
+//! [`TsTypeAnnotation`] is threaded onto the existing AST as optional
+//! fields (an identifier's `type_annotation`, a `Func`'s `return_type`,
+//! a `Func`/`Class`'s `type_params`) so the same node shapes represent
+//! both JS and TS programs, with the TS-specific data simply absent
+//! when the feature is off.
+//!
+//! `Func`/`Class` derive their (de)serialize impls under the
+//! `serialization` feature rather than this crate's usual `serde`
+//! split, so these nodes derive under either one: otherwise building
+//! with `serialization` alone (no `serde`) would serialize a `Func`
+//! whose `return_type`/`type_params` fields can't be.
+use crate::Ident;
+
+/// A TypeScript type.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    any(
+        all(feature = "serde", not(feature = "esprima")),
+        feature = "serialization"
+    ),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), serde(untagged))]
+pub enum TsType<'a> {
+    Number,
+    String,
+    Boolean,
+    Any,
+    Unknown,
+    Void,
+    Null,
+    Undefined,
+    Ref(TsTypeRef<'a>),
+    Union(TsUnion<'a>),
+    Intersection(TsIntersection<'a>),
+    Array(TsArrayType<'a>),
+    Tuple(TsTupleType<'a>),
+    Function(TsFunctionType<'a>),
+    TypeLiteral(TsTypeLiteral<'a>),
+}
+
+/// A named type, optionally applied to type arguments: `Array<string>`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    any(
+        all(feature = "serde", not(feature = "esprima")),
+        feature = "serialization"
+    ),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct TsTypeRef<'a> {
+    pub name: Ident<'a>,
+    pub type_args: Vec<TsType<'a>>,
+}
+
+/// `A | B | C`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    any(
+        all(feature = "serde", not(feature = "esprima")),
+        feature = "serialization"
+    ),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct TsUnion<'a>(pub Vec<TsType<'a>>);
+
+/// `A & B & C`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    any(
+        all(feature = "serde", not(feature = "esprima")),
+        feature = "serialization"
+    ),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct TsIntersection<'a>(pub Vec<TsType<'a>>);
+
+/// `T[]`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    any(
+        all(feature = "serde", not(feature = "esprima")),
+        feature = "serialization"
+    ),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct TsArrayType<'a>(pub Box<TsType<'a>>);
+
+/// `[A, B, C]`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    any(
+        all(feature = "serde", not(feature = "esprima")),
+        feature = "serialization"
+    ),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct TsTupleType<'a>(pub Vec<TsType<'a>>);
+
+/// `(a: string, b: number) => boolean`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    any(
+        all(feature = "serde", not(feature = "esprima")),
+        feature = "serialization"
+    ),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct TsFunctionType<'a> {
+    pub params: Vec<TsType<'a>>,
+    pub return_type: Box<TsType<'a>>,
+}
+
+/// `{ a: string, b: number }`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    any(
+        all(feature = "serde", not(feature = "esprima")),
+        feature = "serialization"
+    ),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct TsTypeLiteral<'a>(pub Vec<TsPropertySignature<'a>>);
+
+/// A single member of a [`TsTypeLiteral`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    any(
+        all(feature = "serde", not(feature = "esprima")),
+        feature = "serialization"
+    ),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct TsPropertySignature<'a> {
+    pub name: Ident<'a>,
+    pub type_annotation: Option<TsTypeAnnotation<'a>>,
+}
+
+/// A `: T` annotation attached to an identifier, parameter or return type.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    any(
+        all(feature = "serde", not(feature = "esprima")),
+        feature = "serialization"
+    ),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct TsTypeAnnotation<'a>(pub Box<TsType<'a>>);
+
+impl<'a> TsTypeAnnotation<'a> {
+    pub fn new(ty: TsType<'a>) -> Self {
+        TsTypeAnnotation(Box::new(ty))
+    }
+}
+
+/// `<T, U>`, the declared type parameters of a `Func` or `Class`, by
+/// name only; `extends` constraints and defaults are not represented.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    any(
+        all(feature = "serde", not(feature = "esprima")),
+        feature = "serialization"
+    ),
+    derive(Deserialize, Serialize)
+)]
+#[cfg_attr(all(feature = "serde", feature = "esprima"), derive(Deserialize))]
+pub struct TsTypeParamDecl<'a>(pub Vec<Ident<'a>>);
@@ -0,0 +1,1054 @@
+//! A generic traversal subsystem over the AST.
+//!
+//! [`Visit`] walks a tree by shared reference, [`VisitMut`] walks it by
+//! `&mut` so a linter or transform can rewrite nodes in place. Both traits
+//! have one method for each of `Program`, `ProgramPart`, `Dir`, `Decl`,
+//! `Stmt`, `Expr`, `Pat`, `Prop`, `Ident`, `Class` and `Func`. Each default
+//! body delegates to a free `walk_*` function, except for the leaves `Dir`
+//! and `Ident`, whose defaults do nothing. Other nodes (variable
+//! declarators, literals, loop heads, JSX nodes and so on) have no method
+//! of their own; their parent's `walk_*` function walks into their
+//! children directly. Overriding a `visit_*` method lets you
+//! inspect or rewrite that node while the default `walk_*` call (if you
+//! still make it) takes care of recursing into its children, so callers
+//! only need to implement the handful of node types they actually care
+//! about.
+use crate::decl::{Decl, DefaultExportDecl, ModExport, ModImport, NamedExportDecl, VarDecl};
+use crate::expr::{
+    ArrayExpr, ArrowFuncBody, ArrowFuncExpr, AssignExpr, AssignLeft, BinaryExpr, CallExpr,
+    ConditionalExpr, Expr, Lit, LogicalExpr, MemberExpr, NewExpr, ObjExpr, ObjProp, Prop,
+    PropKey, PropValue, TaggedTemplateExpr, TemplateLit, UnaryExpr, UpdateExpr, YieldExpr,
+};
+#[cfg(feature = "jsx")]
+use crate::jsx::{JSXAttributeItem, JSXAttributeValue, JSXChild, JSXElement, JSXFragment};
+use crate::pat::{ArrayPatPart, ObjPatPart, Pat};
+use crate::stmt::{
+    BlockStmt, CatchClause, DoWhileStmt, ForInStmt, ForOfStmt, ForStmt, IfStmt, LabeledStmt,
+    LoopInit, LoopLeft, Stmt, SwitchCase, SwitchStmt, TryStmt, WhileStmt, WithStmt,
+};
+use crate::{Class, Dir, Func, FuncArg, FuncBody, Ident, Program, ProgramPart};
+
+/// Walks an AST by shared reference.
+///
+/// Every method except `visit_dir` and `visit_ident` (which do nothing by
+/// default) defaults to calling the matching `walk_*` free function,
+/// which visits the node's children. Override a method to observe (or
+/// short-circuit recursion into) the nodes you care about.
+pub trait Visit<'a> {
+    fn visit_program(&mut self, node: &Program<'a>) {
+        walk_program(self, node);
+    }
+    fn visit_program_part(&mut self, node: &ProgramPart<'a>) {
+        walk_program_part(self, node);
+    }
+    fn visit_dir(&mut self, node: &Dir<'a>) {
+        let _ = node;
+    }
+    fn visit_decl(&mut self, node: &Decl<'a>) {
+        walk_decl(self, node);
+    }
+    fn visit_stmt(&mut self, node: &Stmt<'a>) {
+        walk_stmt(self, node);
+    }
+    fn visit_expr(&mut self, node: &Expr<'a>) {
+        walk_expr(self, node);
+    }
+    fn visit_pat(&mut self, node: &Pat<'a>) {
+        walk_pat(self, node);
+    }
+    fn visit_prop(&mut self, node: &Prop<'a>) {
+        walk_prop(self, node);
+    }
+    fn visit_ident(&mut self, node: &Ident<'a>) {
+        let _ = node;
+    }
+    fn visit_class(&mut self, node: &Class<'a>) {
+        walk_class(self, node);
+    }
+    fn visit_func(&mut self, node: &Func<'a>) {
+        walk_func(self, node);
+    }
+}
+
+/// Walks an AST by mutable reference, allowing nodes to be rewritten
+/// in place.
+///
+/// Mirrors [`Visit`] exactly: the same `visit_*` methods, each defaulting
+/// to the matching `walk_*_mut` function, except `visit_dir` and
+/// `visit_ident`, which do nothing by default.
+pub trait VisitMut<'a> {
+    fn visit_program(&mut self, node: &mut Program<'a>) {
+        walk_program_mut(self, node);
+    }
+    fn visit_program_part(&mut self, node: &mut ProgramPart<'a>) {
+        walk_program_part_mut(self, node);
+    }
+    fn visit_dir(&mut self, node: &mut Dir<'a>) {
+        let _ = node;
+    }
+    fn visit_decl(&mut self, node: &mut Decl<'a>) {
+        walk_decl_mut(self, node);
+    }
+    fn visit_stmt(&mut self, node: &mut Stmt<'a>) {
+        walk_stmt_mut(self, node);
+    }
+    fn visit_expr(&mut self, node: &mut Expr<'a>) {
+        walk_expr_mut(self, node);
+    }
+    fn visit_pat(&mut self, node: &mut Pat<'a>) {
+        walk_pat_mut(self, node);
+    }
+    fn visit_prop(&mut self, node: &mut Prop<'a>) {
+        walk_prop_mut(self, node);
+    }
+    fn visit_ident(&mut self, node: &mut Ident<'a>) {
+        let _ = node;
+    }
+    fn visit_class(&mut self, node: &mut Class<'a>) {
+        walk_class_mut(self, node);
+    }
+    fn visit_func(&mut self, node: &mut Func<'a>) {
+        walk_func_mut(self, node);
+    }
+}
+
+pub fn walk_program<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &Program<'a>) {
+    match node {
+        Program::Mod(parts) | Program::Script(parts) => {
+            for part in parts {
+                v.visit_program_part(part);
+            }
+        }
+    }
+}
+
+pub fn walk_program_part<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &ProgramPart<'a>) {
+    match node {
+        ProgramPart::Dir(dir) => v.visit_dir(dir),
+        ProgramPart::Decl(decl) => v.visit_decl(decl),
+        ProgramPart::Stmt(stmt) => v.visit_stmt(stmt),
+    }
+}
+
+pub fn walk_decl<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &Decl<'a>) {
+    match node {
+        Decl::Variable(_kind, decls) => {
+            for decl in decls {
+                walk_var_decl(v, decl);
+            }
+        }
+        Decl::Function(func) => v.visit_func(func),
+        Decl::Class(class) => v.visit_class(class),
+        Decl::Import(import) => walk_mod_import(v, import),
+        Decl::Export(export) => walk_mod_export(v, export),
+    }
+}
+
+fn walk_var_decl<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &VarDecl<'a>) {
+    v.visit_pat(&node.id);
+    if let Some(init) = &node.init {
+        v.visit_expr(init);
+    }
+}
+
+fn walk_mod_import<'a, V: Visit<'a> + ?Sized>(_v: &mut V, _node: &ModImport<'a>) {
+    // Import specifiers bind identifiers but introduce no child exprs/stmts.
+}
+
+fn walk_mod_export<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &ModExport<'a>) {
+    match node {
+        ModExport::Default(DefaultExportDecl::Decl(decl)) => v.visit_decl(decl),
+        ModExport::Default(DefaultExportDecl::Expr(expr)) => v.visit_expr(expr),
+        ModExport::Named(NamedExportDecl { decl, .. }) => {
+            if let Some(decl) = decl {
+                v.visit_decl(decl);
+            }
+        }
+        ModExport::All { .. } => {}
+    }
+}
+
+pub fn walk_stmt<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &Stmt<'a>) {
+    match node {
+        Stmt::Expr(expr) => v.visit_expr(expr),
+        Stmt::Block(BlockStmt(parts)) => {
+            for part in parts {
+                v.visit_program_part(part);
+            }
+        }
+        Stmt::Empty | Stmt::Debugger => {}
+        Stmt::With(WithStmt { object, body }) => {
+            v.visit_expr(object);
+            v.visit_stmt(body);
+        }
+        Stmt::Return(arg) => {
+            if let Some(arg) = arg {
+                v.visit_expr(arg);
+            }
+        }
+        Stmt::Labeled(LabeledStmt { label, body }) => {
+            v.visit_ident(label);
+            v.visit_stmt(body);
+        }
+        Stmt::Break(label) | Stmt::Continue(label) => {
+            if let Some(label) = label {
+                v.visit_ident(label);
+            }
+        }
+        Stmt::If(IfStmt {
+            test,
+            consequent,
+            alternate,
+        }) => {
+            v.visit_expr(test);
+            v.visit_stmt(consequent);
+            if let Some(alternate) = alternate {
+                v.visit_stmt(alternate);
+            }
+        }
+        Stmt::Switch(SwitchStmt {
+            discriminant,
+            cases,
+        }) => {
+            v.visit_expr(discriminant);
+            for SwitchCase { test, consequent } in cases {
+                if let Some(test) = test {
+                    v.visit_expr(test);
+                }
+                for part in consequent {
+                    v.visit_program_part(part);
+                }
+            }
+        }
+        Stmt::Throw(arg) => v.visit_expr(arg),
+        Stmt::Try(TryStmt {
+            block,
+            handler,
+            finalizer,
+        }) => {
+            for part in &block.0 {
+                v.visit_program_part(part);
+            }
+            if let Some(CatchClause { param, body }) = handler {
+                if let Some(param) = param {
+                    v.visit_pat(param);
+                }
+                for part in &body.0 {
+                    v.visit_program_part(part);
+                }
+            }
+            if let Some(finalizer) = finalizer {
+                for part in &finalizer.0 {
+                    v.visit_program_part(part);
+                }
+            }
+        }
+        Stmt::While(WhileStmt { test, body }) => {
+            v.visit_expr(test);
+            v.visit_stmt(body);
+        }
+        Stmt::DoWhile(DoWhileStmt { test, body }) => {
+            v.visit_expr(test);
+            v.visit_stmt(body);
+        }
+        Stmt::For(ForStmt {
+            init,
+            test,
+            update,
+            body,
+        }) => {
+            if let Some(init) = init {
+                match init {
+                    LoopInit::Variable(decls) => {
+                        for decl in decls {
+                            walk_var_decl(v, decl);
+                        }
+                    }
+                    LoopInit::Expr(expr) => v.visit_expr(expr),
+                }
+            }
+            if let Some(test) = test {
+                v.visit_expr(test);
+            }
+            if let Some(update) = update {
+                v.visit_expr(update);
+            }
+            v.visit_stmt(body);
+        }
+        Stmt::ForIn(ForInStmt { left, right, body }) => {
+            walk_loop_left(v, left);
+            v.visit_expr(right);
+            v.visit_stmt(body);
+        }
+        Stmt::ForOf(ForOfStmt {
+            left,
+            right,
+            body,
+            is_await: _,
+        }) => {
+            walk_loop_left(v, left);
+            v.visit_expr(right);
+            v.visit_stmt(body);
+        }
+    }
+}
+
+fn walk_loop_left<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &LoopLeft<'a>) {
+    match node {
+        LoopLeft::Expr(expr) => v.visit_expr(expr),
+        LoopLeft::Pat(pat) => v.visit_pat(pat),
+        LoopLeft::Variable(_kind, pat) => v.visit_pat(pat),
+    }
+}
+
+pub fn walk_expr<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &Expr<'a>) {
+    match node {
+        Expr::Array(ArrayExpr(elements)) => {
+            for element in elements.iter().flatten() {
+                v.visit_expr(element);
+            }
+        }
+        Expr::ArrowFunc(ArrowFuncExpr { id, params, body, .. }) => {
+            if let Some(id) = id {
+                v.visit_ident(id);
+            }
+            for param in params {
+                walk_func_arg(v, param);
+            }
+            match body {
+                ArrowFuncBody::Expr(expr) => v.visit_expr(expr),
+                ArrowFuncBody::FuncBody(FuncBody(parts)) => {
+                    for part in parts {
+                        v.visit_program_part(part);
+                    }
+                }
+            }
+        }
+        Expr::Assign(AssignExpr {
+            operator: _,
+            left,
+            right,
+        }) => {
+            match left {
+                AssignLeft::Pat(pat) => v.visit_pat(pat),
+                AssignLeft::Expr(expr) => v.visit_expr(expr),
+            }
+            v.visit_expr(right);
+        }
+        Expr::Await(arg) => v.visit_expr(arg),
+        Expr::Binary(BinaryExpr {
+            operator: _,
+            left,
+            right,
+        }) => {
+            v.visit_expr(left);
+            v.visit_expr(right);
+        }
+        Expr::Class(class) => v.visit_class(class),
+        Expr::Call(CallExpr { callee, arguments }) => {
+            v.visit_expr(callee);
+            for arg in arguments {
+                v.visit_expr(arg);
+            }
+        }
+        Expr::Conditional(ConditionalExpr {
+            test,
+            alternate,
+            consequent,
+        }) => {
+            v.visit_expr(test);
+            v.visit_expr(alternate);
+            v.visit_expr(consequent);
+        }
+        Expr::Func(func) => v.visit_func(func),
+        Expr::Ident(ident) => v.visit_ident(ident),
+        Expr::Lit(lit) => walk_lit(v, lit),
+        Expr::Logical(LogicalExpr {
+            operator: _,
+            left,
+            right,
+        }) => {
+            v.visit_expr(left);
+            v.visit_expr(right);
+        }
+        Expr::Member(MemberExpr {
+            object,
+            property,
+            computed: _,
+        }) => {
+            v.visit_expr(object);
+            v.visit_expr(property);
+        }
+        Expr::MetaProp(_) => {}
+        Expr::New(NewExpr { callee, arguments }) => {
+            v.visit_expr(callee);
+            for arg in arguments {
+                v.visit_expr(arg);
+            }
+        }
+        Expr::Obj(ObjExpr(props)) => {
+            for prop in props {
+                match prop {
+                    ObjProp::Prop(prop) => v.visit_prop(prop),
+                    ObjProp::Spread(expr) => v.visit_expr(expr),
+                }
+            }
+        }
+        Expr::Sequence(exprs) => {
+            for expr in exprs {
+                v.visit_expr(expr);
+            }
+        }
+        Expr::Spread(arg) => v.visit_expr(arg),
+        Expr::Super | Expr::This => {}
+        Expr::TaggedTemplate(TaggedTemplateExpr { tag, quasi }) => {
+            v.visit_expr(tag);
+            for expr in &quasi.expressions {
+                v.visit_expr(expr);
+            }
+        }
+        Expr::Unary(UnaryExpr { argument, .. }) => v.visit_expr(argument),
+        Expr::Update(UpdateExpr { argument, .. }) => v.visit_expr(argument),
+        Expr::Wrapped(expr) => v.visit_expr(expr),
+        Expr::Yield(YieldExpr { argument, .. }) => {
+            if let Some(argument) = argument {
+                v.visit_expr(argument);
+            }
+        }
+        #[cfg(feature = "jsx")]
+        Expr::JSXElement(element) => walk_jsx_element(v, element),
+        #[cfg(feature = "jsx")]
+        Expr::JSXFragment(fragment) => walk_jsx_fragment(v, fragment),
+    }
+}
+
+fn walk_lit<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &Lit<'a>) {
+    if let Lit::Template(TemplateLit { expressions, .. }) = node {
+        for expr in expressions {
+            v.visit_expr(expr);
+        }
+    }
+}
+
+#[cfg(feature = "jsx")]
+fn walk_jsx_element<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &JSXElement<'a>) {
+    for attribute in &node.opening_element.attributes {
+        match attribute {
+            JSXAttributeItem::Attribute(attribute) => match &attribute.value {
+                Some(JSXAttributeValue::Container(container)) => v.visit_expr(&container.0),
+                Some(JSXAttributeValue::Element(element)) => walk_jsx_element(v, element),
+                Some(JSXAttributeValue::Str(_)) | None => {}
+            },
+            JSXAttributeItem::Spread(spread) => v.visit_expr(&spread.argument),
+        }
+    }
+    for child in &node.children {
+        walk_jsx_child(v, child);
+    }
+}
+
+#[cfg(feature = "jsx")]
+fn walk_jsx_fragment<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &JSXFragment<'a>) {
+    for child in &node.children {
+        walk_jsx_child(v, child);
+    }
+}
+
+#[cfg(feature = "jsx")]
+fn walk_jsx_child<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &JSXChild<'a>) {
+    match node {
+        JSXChild::Element(element) => walk_jsx_element(v, element),
+        JSXChild::Fragment(fragment) => walk_jsx_fragment(v, fragment),
+        JSXChild::ExpressionContainer(container) => v.visit_expr(&container.0),
+        JSXChild::Text(_) => {}
+    }
+}
+
+fn walk_func_arg<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &FuncArg<'a>) {
+    match node {
+        FuncArg::Expr(expr) => v.visit_expr(expr),
+        FuncArg::Pat(pat) => v.visit_pat(pat),
+    }
+}
+
+pub fn walk_pat<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &Pat<'a>) {
+    match node {
+        Pat::Ident(ident) => v.visit_ident(ident),
+        Pat::Obj(parts) => {
+            for part in parts {
+                match part {
+                    ObjPatPart::Assign(prop) => v.visit_prop(prop),
+                    ObjPatPart::Rest(pat) => v.visit_pat(pat),
+                }
+            }
+        }
+        Pat::Array(parts) => {
+            for part in parts.iter().flatten() {
+                match part {
+                    ArrayPatPart::Pat(pat) => v.visit_pat(pat),
+                    ArrayPatPart::Expr(expr) => v.visit_expr(expr),
+                }
+            }
+        }
+        Pat::RestElement(pat) => v.visit_pat(pat),
+        Pat::Assign(assign) => {
+            v.visit_pat(&assign.left);
+            v.visit_expr(&assign.right);
+        }
+    }
+}
+
+pub fn walk_prop<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &Prop<'a>) {
+    match &node.key {
+        PropKey::Ident(ident) => v.visit_ident(ident),
+        PropKey::Pat(pat) => v.visit_pat(pat),
+        PropKey::Lit(lit) => walk_lit(v, lit),
+    }
+    match &node.value {
+        PropValue::Expr(expr) => v.visit_expr(expr),
+        PropValue::Pat(pat) => v.visit_pat(pat),
+        PropValue::None => {}
+    }
+}
+
+pub fn walk_class<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &Class<'a>) {
+    if let Some(id) = &node.id {
+        v.visit_ident(id);
+    }
+    if let Some(super_class) = &node.super_class {
+        v.visit_expr(super_class);
+    }
+    for prop in &node.body.0 {
+        v.visit_prop(prop);
+    }
+}
+
+pub fn walk_func<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &Func<'a>) {
+    if let Some(id) = &node.id {
+        v.visit_ident(id);
+    }
+    for param in &node.params {
+        walk_func_arg(v, param);
+    }
+    for part in &node.body.0 {
+        v.visit_program_part(part);
+    }
+}
+
+pub fn walk_program_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut Program<'a>) {
+    match node {
+        Program::Mod(parts) | Program::Script(parts) => {
+            for part in parts {
+                v.visit_program_part(part);
+            }
+        }
+    }
+}
+
+pub fn walk_program_part_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut ProgramPart<'a>) {
+    match node {
+        ProgramPart::Dir(dir) => v.visit_dir(dir),
+        ProgramPart::Decl(decl) => v.visit_decl(decl),
+        ProgramPart::Stmt(stmt) => v.visit_stmt(stmt),
+    }
+}
+
+pub fn walk_decl_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut Decl<'a>) {
+    match node {
+        Decl::Variable(_kind, decls) => {
+            for decl in decls {
+                walk_var_decl_mut(v, decl);
+            }
+        }
+        Decl::Function(func) => v.visit_func(func),
+        Decl::Class(class) => v.visit_class(class),
+        Decl::Import(import) => walk_mod_import_mut(v, import),
+        Decl::Export(export) => walk_mod_export_mut(v, export),
+    }
+}
+
+fn walk_mod_import_mut<'a, V: VisitMut<'a> + ?Sized>(_v: &mut V, _node: &mut ModImport<'a>) {
+    // Import specifiers bind identifiers but introduce no child exprs/stmts.
+}
+
+fn walk_mod_export_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut ModExport<'a>) {
+    match node {
+        ModExport::Default(DefaultExportDecl::Decl(decl)) => v.visit_decl(decl),
+        ModExport::Default(DefaultExportDecl::Expr(expr)) => v.visit_expr(expr),
+        ModExport::Named(NamedExportDecl { decl, .. }) => {
+            if let Some(decl) = decl {
+                v.visit_decl(decl);
+            }
+        }
+        ModExport::All { .. } => {}
+    }
+}
+
+fn walk_var_decl_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut VarDecl<'a>) {
+    v.visit_pat(&mut node.id);
+    if let Some(init) = &mut node.init {
+        v.visit_expr(init);
+    }
+}
+
+pub fn walk_stmt_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut Stmt<'a>) {
+    match node {
+        Stmt::Expr(expr) => v.visit_expr(expr),
+        Stmt::Block(BlockStmt(parts)) => {
+            for part in parts {
+                v.visit_program_part(part);
+            }
+        }
+        Stmt::Empty | Stmt::Debugger => {}
+        Stmt::With(WithStmt { object, body }) => {
+            v.visit_expr(object);
+            v.visit_stmt(body);
+        }
+        Stmt::Return(arg) => {
+            if let Some(arg) = arg {
+                v.visit_expr(arg);
+            }
+        }
+        Stmt::Labeled(LabeledStmt { label, body }) => {
+            v.visit_ident(label);
+            v.visit_stmt(body);
+        }
+        Stmt::Break(label) | Stmt::Continue(label) => {
+            if let Some(label) = label {
+                v.visit_ident(label);
+            }
+        }
+        Stmt::If(IfStmt {
+            test,
+            consequent,
+            alternate,
+        }) => {
+            v.visit_expr(test);
+            v.visit_stmt(consequent);
+            if let Some(alternate) = alternate {
+                v.visit_stmt(alternate);
+            }
+        }
+        Stmt::Switch(SwitchStmt {
+            discriminant,
+            cases,
+        }) => {
+            v.visit_expr(discriminant);
+            for SwitchCase { test, consequent } in cases {
+                if let Some(test) = test {
+                    v.visit_expr(test);
+                }
+                for part in consequent {
+                    v.visit_program_part(part);
+                }
+            }
+        }
+        Stmt::Throw(arg) => v.visit_expr(arg),
+        Stmt::Try(TryStmt {
+            block,
+            handler,
+            finalizer,
+        }) => {
+            for part in &mut block.0 {
+                v.visit_program_part(part);
+            }
+            if let Some(CatchClause { param, body }) = handler {
+                if let Some(param) = param {
+                    v.visit_pat(param);
+                }
+                for part in &mut body.0 {
+                    v.visit_program_part(part);
+                }
+            }
+            if let Some(finalizer) = finalizer {
+                for part in &mut finalizer.0 {
+                    v.visit_program_part(part);
+                }
+            }
+        }
+        Stmt::While(WhileStmt { test, body }) => {
+            v.visit_expr(test);
+            v.visit_stmt(body);
+        }
+        Stmt::DoWhile(DoWhileStmt { test, body }) => {
+            v.visit_expr(test);
+            v.visit_stmt(body);
+        }
+        Stmt::For(ForStmt {
+            init,
+            test,
+            update,
+            body,
+        }) => {
+            if let Some(init) = init {
+                match init {
+                    LoopInit::Variable(decls) => {
+                        for decl in decls {
+                            walk_var_decl_mut(v, decl);
+                        }
+                    }
+                    LoopInit::Expr(expr) => v.visit_expr(expr),
+                }
+            }
+            if let Some(test) = test {
+                v.visit_expr(test);
+            }
+            if let Some(update) = update {
+                v.visit_expr(update);
+            }
+            v.visit_stmt(body);
+        }
+        Stmt::ForIn(ForInStmt { left, right, body }) => {
+            walk_loop_left_mut(v, left);
+            v.visit_expr(right);
+            v.visit_stmt(body);
+        }
+        Stmt::ForOf(ForOfStmt {
+            left,
+            right,
+            body,
+            is_await: _,
+        }) => {
+            walk_loop_left_mut(v, left);
+            v.visit_expr(right);
+            v.visit_stmt(body);
+        }
+    }
+}
+
+fn walk_loop_left_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut LoopLeft<'a>) {
+    match node {
+        LoopLeft::Expr(expr) => v.visit_expr(expr),
+        LoopLeft::Pat(pat) => v.visit_pat(pat),
+        LoopLeft::Variable(_kind, pat) => v.visit_pat(pat),
+    }
+}
+
+pub fn walk_expr_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut Expr<'a>) {
+    match node {
+        Expr::Array(ArrayExpr(elements)) => {
+            for element in elements.iter_mut().flatten() {
+                v.visit_expr(element);
+            }
+        }
+        Expr::ArrowFunc(ArrowFuncExpr { id, params, body, .. }) => {
+            if let Some(id) = id {
+                v.visit_ident(id);
+            }
+            for param in params {
+                walk_func_arg_mut(v, param);
+            }
+            match body {
+                ArrowFuncBody::Expr(expr) => v.visit_expr(expr),
+                ArrowFuncBody::FuncBody(FuncBody(parts)) => {
+                    for part in parts {
+                        v.visit_program_part(part);
+                    }
+                }
+            }
+        }
+        Expr::Assign(AssignExpr {
+            operator: _,
+            left,
+            right,
+        }) => {
+            match left {
+                AssignLeft::Pat(pat) => v.visit_pat(pat),
+                AssignLeft::Expr(expr) => v.visit_expr(expr),
+            }
+            v.visit_expr(right);
+        }
+        Expr::Await(arg) => v.visit_expr(arg),
+        Expr::Binary(BinaryExpr {
+            operator: _,
+            left,
+            right,
+        }) => {
+            v.visit_expr(left);
+            v.visit_expr(right);
+        }
+        Expr::Class(class) => v.visit_class(class),
+        Expr::Call(CallExpr { callee, arguments }) => {
+            v.visit_expr(callee);
+            for arg in arguments {
+                v.visit_expr(arg);
+            }
+        }
+        Expr::Conditional(ConditionalExpr {
+            test,
+            alternate,
+            consequent,
+        }) => {
+            v.visit_expr(test);
+            v.visit_expr(alternate);
+            v.visit_expr(consequent);
+        }
+        Expr::Func(func) => v.visit_func(func),
+        Expr::Ident(ident) => v.visit_ident(ident),
+        Expr::Lit(lit) => walk_lit_mut(v, lit),
+        Expr::Logical(LogicalExpr {
+            operator: _,
+            left,
+            right,
+        }) => {
+            v.visit_expr(left);
+            v.visit_expr(right);
+        }
+        Expr::Member(MemberExpr {
+            object,
+            property,
+            computed: _,
+        }) => {
+            v.visit_expr(object);
+            v.visit_expr(property);
+        }
+        Expr::MetaProp(_) => {}
+        Expr::New(NewExpr { callee, arguments }) => {
+            v.visit_expr(callee);
+            for arg in arguments {
+                v.visit_expr(arg);
+            }
+        }
+        Expr::Obj(ObjExpr(props)) => {
+            for prop in props {
+                match prop {
+                    ObjProp::Prop(prop) => v.visit_prop(prop),
+                    ObjProp::Spread(expr) => v.visit_expr(expr),
+                }
+            }
+        }
+        Expr::Sequence(exprs) => {
+            for expr in exprs {
+                v.visit_expr(expr);
+            }
+        }
+        Expr::Spread(arg) => v.visit_expr(arg),
+        Expr::Super | Expr::This => {}
+        Expr::TaggedTemplate(TaggedTemplateExpr { tag, quasi }) => {
+            v.visit_expr(tag);
+            for expr in &mut quasi.expressions {
+                v.visit_expr(expr);
+            }
+        }
+        Expr::Unary(UnaryExpr { argument, .. }) => v.visit_expr(argument),
+        Expr::Update(UpdateExpr { argument, .. }) => v.visit_expr(argument),
+        Expr::Wrapped(expr) => v.visit_expr(expr),
+        Expr::Yield(YieldExpr { argument, .. }) => {
+            if let Some(argument) = argument {
+                v.visit_expr(argument);
+            }
+        }
+        #[cfg(feature = "jsx")]
+        Expr::JSXElement(element) => walk_jsx_element_mut(v, element),
+        #[cfg(feature = "jsx")]
+        Expr::JSXFragment(fragment) => walk_jsx_fragment_mut(v, fragment),
+    }
+}
+
+fn walk_lit_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut Lit<'a>) {
+    if let Lit::Template(TemplateLit { expressions, .. }) = node {
+        for expr in expressions {
+            v.visit_expr(expr);
+        }
+    }
+}
+
+#[cfg(feature = "jsx")]
+fn walk_jsx_element_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut JSXElement<'a>) {
+    for attribute in &mut node.opening_element.attributes {
+        match attribute {
+            JSXAttributeItem::Attribute(attribute) => match &mut attribute.value {
+                Some(JSXAttributeValue::Container(container)) => v.visit_expr(&mut container.0),
+                Some(JSXAttributeValue::Element(element)) => walk_jsx_element_mut(v, element),
+                Some(JSXAttributeValue::Str(_)) | None => {}
+            },
+            JSXAttributeItem::Spread(spread) => v.visit_expr(&mut spread.argument),
+        }
+    }
+    for child in &mut node.children {
+        walk_jsx_child_mut(v, child);
+    }
+}
+
+#[cfg(feature = "jsx")]
+fn walk_jsx_fragment_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut JSXFragment<'a>) {
+    for child in &mut node.children {
+        walk_jsx_child_mut(v, child);
+    }
+}
+
+#[cfg(feature = "jsx")]
+fn walk_jsx_child_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut JSXChild<'a>) {
+    match node {
+        JSXChild::Element(element) => walk_jsx_element_mut(v, element),
+        JSXChild::Fragment(fragment) => walk_jsx_fragment_mut(v, fragment),
+        JSXChild::ExpressionContainer(container) => v.visit_expr(&mut container.0),
+        JSXChild::Text(_) => {}
+    }
+}
+
+fn walk_func_arg_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut FuncArg<'a>) {
+    match node {
+        FuncArg::Expr(expr) => v.visit_expr(expr),
+        FuncArg::Pat(pat) => v.visit_pat(pat),
+    }
+}
+
+pub fn walk_pat_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut Pat<'a>) {
+    match node {
+        Pat::Ident(ident) => v.visit_ident(ident),
+        Pat::Obj(parts) => {
+            for part in parts {
+                match part {
+                    ObjPatPart::Assign(prop) => v.visit_prop(prop),
+                    ObjPatPart::Rest(pat) => v.visit_pat(pat),
+                }
+            }
+        }
+        Pat::Array(parts) => {
+            for part in parts.iter_mut().flatten() {
+                match part {
+                    ArrayPatPart::Pat(pat) => v.visit_pat(pat),
+                    ArrayPatPart::Expr(expr) => v.visit_expr(expr),
+                }
+            }
+        }
+        Pat::RestElement(pat) => v.visit_pat(pat),
+        Pat::Assign(assign) => {
+            v.visit_pat(&mut assign.left);
+            v.visit_expr(&mut assign.right);
+        }
+    }
+}
+
+pub fn walk_prop_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut Prop<'a>) {
+    match &mut node.key {
+        PropKey::Ident(ident) => v.visit_ident(ident),
+        PropKey::Pat(pat) => v.visit_pat(pat),
+        PropKey::Lit(lit) => walk_lit_mut(v, lit),
+    }
+    match &mut node.value {
+        PropValue::Expr(expr) => v.visit_expr(expr),
+        PropValue::Pat(pat) => v.visit_pat(pat),
+        PropValue::None => {}
+    }
+}
+
+pub fn walk_class_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut Class<'a>) {
+    if let Some(id) = &mut node.id {
+        v.visit_ident(id);
+    }
+    if let Some(super_class) = &mut node.super_class {
+        v.visit_expr(super_class);
+    }
+    for prop in &mut node.body.0 {
+        v.visit_prop(prop);
+    }
+}
+
+pub fn walk_func_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut Func<'a>) {
+    if let Some(id) = &mut node.id {
+        v.visit_ident(id);
+    }
+    for param in &mut node.params {
+        walk_func_arg_mut(v, param);
+    }
+    for part in &mut node.body.0 {
+        v.visit_program_part(part);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decl::VarDecl;
+    use crate::{expr_node, BinaryOp, Program, PropKind, VarKind};
+
+    fn sample_program<'a>() -> Program<'a> {
+        Program::script(vec![
+            // let { a: b } = c;
+            ProgramPart::decl(Decl::Variable(
+                VarKind::Let,
+                vec![VarDecl {
+                    id: Pat::Obj(vec![ObjPatPart::Assign(Prop {
+                        key: PropKey::Ident(Ident::from("a")),
+                        value: PropValue::Pat(Pat::Ident(Ident::from("b"))),
+                        kind: PropKind::Init,
+                        method: false,
+                        computed: false,
+                        short_hand: false,
+                    })]),
+                    init: Some(Expr::Ident(Ident::from("c"))),
+                }],
+            )),
+            // let sum = d + e;
+            ProgramPart::decl(Decl::Variable(
+                VarKind::Let,
+                vec![VarDecl {
+                    id: Pat::Ident(Ident::from("sum")),
+                    init: Some(Expr::Binary(BinaryExpr {
+                        operator: BinaryOp::Plus,
+                        left: expr_node(Expr::Ident(Ident::from("d"))),
+                        right: expr_node(Expr::Ident(Ident::from("e"))),
+                    })),
+                }],
+            )),
+            ProgramPart::decl(Decl::Function(Func::new(
+                Some(Ident::from("f")),
+                vec![FuncArg::Pat(Pat::Ident(Ident::from("x")))],
+                FuncBody(vec![]),
+                false,
+                false,
+            ))),
+        ])
+    }
+
+    /// Collects the name of every identifier visited, in traversal order.
+    #[derive(Default)]
+    struct IdentCollector<'a> {
+        names: Vec<std::borrow::Cow<'a, str>>,
+    }
+
+    impl<'a> Visit<'a> for IdentCollector<'a> {
+        fn visit_ident(&mut self, node: &Ident<'a>) {
+            self.names.push(node.name.clone());
+        }
+    }
+
+    /// Counts every `Expr` node visited, overriding `visit_expr` but still
+    /// calling `walk_expr` so traversal continues into its children.
+    #[derive(Default)]
+    struct ExprCounter {
+        count: usize,
+    }
+
+    impl<'a> Visit<'a> for ExprCounter {
+        fn visit_expr(&mut self, node: &Expr<'a>) {
+            self.count += 1;
+            walk_expr(self, node);
+        }
+    }
+
+    #[test]
+    fn ident_collector_visits_every_identifier() {
+        let program = sample_program();
+        let mut collector = IdentCollector::default();
+        collector.visit_program(&program);
+        assert_eq!(
+            collector.names,
+            vec!["a", "b", "c", "sum", "d", "e", "f", "x"]
+        );
+    }
+
+    #[test]
+    fn expr_counter_counts_nested_exprs() {
+        let program = sample_program();
+        let mut counter = ExprCounter::default();
+        counter.visit_program(&program);
+        // `c` (1) plus the `d + e` BinaryExpr itself and each of its
+        // two operands (3), for a total of 4 — proving the counter
+        // actually recurses into a nested expression rather than only
+        // seeing top-level ones.
+        assert_eq!(counter.count, 4);
+    }
+}